@@ -0,0 +1,88 @@
+use anyhow::Result as AnyResult;
+use ordered_float::NotNan;
+
+use crate::interface::{Problem, Score, Variable};
+
+/// Total constraint violation of a point. `0` means feasible; larger is worse.
+pub type Violation = NotNan<f64>;
+
+/// Whether the objective should be minimized or maximized.
+///
+/// Threading this through the selector lets users optimize in either direction
+/// without negating their objective by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OptimizationDirection {
+    #[default]
+    Minimize,
+    Maximize,
+}
+
+impl OptimizationDirection {
+    /// `true` when `candidate` is strictly better than `incumbent` in this
+    /// direction.
+    pub fn is_better(&self, candidate: Score, incumbent: Score) -> bool {
+        match self {
+            OptimizationDirection::Minimize => candidate < incumbent,
+            OptimizationDirection::Maximize => candidate > incumbent,
+        }
+    }
+}
+
+/// Builds the initial population and evaluates it.
+pub trait Initializer {
+    fn initialize(
+        &self,
+        problem: &dyn Problem,
+        population_size: usize,
+    ) -> AnyResult<(Vec<Score>, Vec<Violation>, Vec<Variable>)>;
+}
+
+/// Per-call context a mutation operator may need beyond the population itself,
+/// such as which individual is being mutated and where the current best lies.
+#[derive(Debug, Clone, Copy)]
+pub struct MutationContext {
+    /// Index of the individual currently being mutated.
+    pub current_index: usize,
+    /// Index of the current best individual in the population.
+    pub best_index: usize,
+}
+
+/// Produces a mutant (donor) vector from the current population.
+///
+/// Takes `&mut self` so the operator can advance its own seedable RNG, keeping
+/// a whole run reproducible from a single master seed. The [`MutationContext`]
+/// carries the indices that best/current-based strategies need; strategies that
+/// only use random members (e.g. DE/rand/1) may ignore it.
+pub trait MutationOperator {
+    fn mutate_one(
+        &mut self,
+        current_population: &[Variable],
+        context: MutationContext,
+    ) -> AnyResult<Variable>;
+}
+
+/// Mixes a parent with its mutant to produce a trial vector.
+pub trait CrossoverOperator {
+    fn crossover_one(&mut self, v_current: &Variable, v_mutant: &Variable) -> AnyResult<Variable>;
+}
+
+/// Decides whether the trial vector replaces its parent.
+///
+/// The current score *and* violation are passed in so the comparison never has
+/// to re-evaluate the parent; the chosen score and violation are returned so the
+/// driving loop can carry them into the next generation.
+pub trait Selector {
+    fn select_one(
+        &self,
+        problem: &dyn Problem,
+        s_current: Score,
+        g_current: Violation,
+        v_current: Variable,
+        v_trial: Variable,
+    ) -> AnyResult<(Score, Violation, Variable)>;
+
+    /// The optimization direction this selector accepts trials in. The solver
+    /// reads it so the reported best and the best-based mutation strategies
+    /// agree with the acceptance test — a single source of truth.
+    fn direction(&self) -> OptimizationDirection;
+}
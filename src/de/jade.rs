@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+
+use anyhow::Result as AnyResult;
+use ordered_float::NotNan;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::interface::{Problem, Score, Variable};
+
+use super::base::{OptimizationDirection, Violation};
+
+/// JADE: self-adaptive differential evolution with "current-to-pbest/1" mutation
+/// and an external archive.
+///
+/// The mutation scale `F` and crossover rate `CR` are not tuned by hand; instead
+/// two scalar means `μF` and `μCR` adapt from the control parameters that
+/// produced successful trials. Because the scheme couples mutation, crossover,
+/// selection and per-individual parameter state, it is implemented as one
+/// cohesive driver rather than through the generic operator traits.
+pub struct Jade<R = StdRng> {
+    bounds: Vec<(NotNan<f64>, NotNan<f64>)>,
+    /// Top fraction of the population eligible as `x_pbest` (JADE's `p`).
+    p: f64,
+    /// Adaptation rate of the control-parameter means (JADE's `c`).
+    c: f64,
+    direction: OptimizationDirection,
+    rng: R,
+}
+
+impl Jade<StdRng> {
+    pub fn new(bounds: Vec<(NotNan<f64>, NotNan<f64>)>) -> Self {
+        Self::with_seed(bounds, StdRng::from_os_rng().random())
+    }
+}
+
+impl<R: Rng + SeedableRng> Jade<R> {
+    pub fn with_seed(bounds: Vec<(NotNan<f64>, NotNan<f64>)>, seed: u64) -> Self {
+        Self {
+            bounds,
+            p: 0.1,
+            c: 0.1,
+            direction: OptimizationDirection::Minimize,
+            rng: R::seed_from_u64(seed),
+        }
+    }
+
+    pub fn with_direction(mut self, direction: OptimizationDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn with_p(mut self, p: f64) -> Self {
+        self.p = p;
+        self
+    }
+
+    pub fn with_c(mut self, c: f64) -> Self {
+        self.c = c;
+        self
+    }
+}
+
+impl<R: Rng> Jade<R> {
+    /// Samples from `Normal(mean, std)` via the Box–Muller transform.
+    fn sample_normal(&mut self, mean: f64, std: f64) -> f64 {
+        let u1: f64 = self.rng.random_range(f64::MIN_POSITIVE..1.0);
+        let u2: f64 = self.rng.random_range(0.0..1.0);
+        mean + std * (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+
+    /// Samples from `Cauchy(location, scale)` via the inverse CDF.
+    fn sample_cauchy(&mut self, location: f64, scale: f64) -> f64 {
+        let u: f64 = self.rng.random_range(0.0..1.0);
+        location + scale * (PI * (u - 0.5)).tan()
+    }
+
+    /// Runs `n_iter` generations and returns the best `(score, violation, variable)`.
+    pub fn solve(
+        &mut self,
+        problem: &dyn Problem,
+        population_size: usize,
+        n_iter: usize,
+    ) -> AnyResult<(Score, Violation, Variable)> {
+        // current-to-pbest/1 draws x_r1 distinct from x_i and x_pbest, and
+        // x_r2 distinct from x_i and x_r1; x_pbest may coincide with x_i or
+        // x_r2. The population must hold at least four individuals.
+        anyhow::ensure!(
+            population_size >= 4,
+            "JADE requires a population size of at least 4, got {population_size}"
+        );
+
+        let mut variables = Vec::with_capacity(population_size);
+        let mut scores = Vec::with_capacity(population_size);
+        let mut violations = Vec::with_capacity(population_size);
+        for _ in 0..population_size {
+            let x = self.random_point();
+            scores.push(problem.evaluate(&x)?);
+            violations.push(problem.violation(&x)?);
+            variables.push(x);
+        }
+
+        let mut archive: Vec<Variable> = Vec::new();
+        let mut mu_f = 0.5;
+        let mut mu_cr = 0.5;
+
+        for _ in 0..n_iter {
+            let ranked = self.ranked_indices(&scores, &violations);
+            let top = (self.p * population_size as f64).round().max(1.0) as usize;
+
+            let mut success_f: Vec<f64> = Vec::new();
+            let mut success_cr: Vec<f64> = Vec::new();
+
+            for i in 0..population_size {
+                let cr_i = self.sample_normal(mu_cr, 0.1).clamp(0.0, 1.0);
+                let mut f_i = self.sample_cauchy(mu_f, 0.1);
+                while f_i <= 0.0 {
+                    f_i = self.sample_cauchy(mu_f, 0.1);
+                }
+                f_i = f_i.min(1.0);
+
+                let pbest = ranked[self.rng.random_range(0..top)];
+                // r1 distinct from i, drawn from the population
+                let r1 = self.sample_index(population_size, &[i, pbest]);
+                // r2 distinct from i and r1, drawn from population ∪ archive
+                let r2 = self.sample_index(population_size + archive.len(), &[i, r1]);
+
+                let x_r2 = if r2 < population_size {
+                    &variables[r2]
+                } else {
+                    &archive[r2 - population_size]
+                };
+                let base = &variables[i] + f_i * (&variables[pbest] - &variables[i]);
+                let mutant = &base + f_i * (&variables[r1] - x_r2);
+
+                let trial = self.crossover(&variables[i], &mutant, cr_i);
+                let s_trial = problem.evaluate(&trial)?;
+                let g_trial = problem.violation(&trial)?;
+
+                if self.trial_wins(s_trial, g_trial, scores[i], violations[i]) {
+                    // recycle the parent into the bounded archive
+                    self.push_archive(&mut archive, variables[i].clone(), population_size);
+                    success_f.push(f_i);
+                    success_cr.push(cr_i);
+                    variables[i] = trial;
+                    scores[i] = s_trial;
+                    violations[i] = g_trial;
+                }
+            }
+
+            if !success_cr.is_empty() {
+                let mean_cr = success_cr.iter().sum::<f64>() / success_cr.len() as f64;
+                mu_cr = (1.0 - self.c) * mu_cr + self.c * mean_cr;
+            }
+            if !success_f.is_empty() {
+                let lehmer = success_f.iter().map(|f| f * f).sum::<f64>()
+                    / success_f.iter().sum::<f64>();
+                mu_f = (1.0 - self.c) * mu_f + self.c * lehmer;
+            }
+        }
+
+        let best = self.ranked_indices(&scores, &violations)[0];
+        Ok((scores[best], violations[best], variables[best].clone()))
+    }
+
+    fn random_point(&mut self) -> Variable {
+        let values = self
+            .bounds
+            .iter()
+            .map(|(lower, upper)| self.rng.random_range(lower.into_inner()..upper.into_inner()))
+            .collect();
+        Variable::from_vec(values)
+    }
+
+    /// Binomial crossover with a guaranteed gene from the mutant (`j_rand`).
+    fn crossover(&mut self, parent: &Variable, mutant: &Variable, cr: f64) -> Variable {
+        let dim = parent.len();
+        let j_rand = self.rng.random_range(0..dim);
+        let values = parent
+            .iter()
+            .zip(mutant.iter())
+            .enumerate()
+            .map(|(j, (x_p, x_m))| {
+                if j == j_rand || self.rng.random_range(0.0..1.0) < cr {
+                    *x_m
+                } else {
+                    *x_p
+                }
+            })
+            .collect();
+        Variable::from_vec(values)
+    }
+
+    /// Draws a single index from `0..n` that is not in `exclude`.
+    fn sample_index(&mut self, n: usize, exclude: &[usize]) -> usize {
+        loop {
+            let index = self.rng.random_range(0..n);
+            if !exclude.contains(&index) {
+                return index;
+            }
+        }
+    }
+
+    fn push_archive(&mut self, archive: &mut Vec<Variable>, parent: Variable, cap: usize) {
+        if archive.len() < cap {
+            archive.push(parent);
+        } else {
+            let victim = self.rng.random_range(0..archive.len());
+            archive[victim] = parent;
+        }
+    }
+
+    /// Indices sorted best-first under Deb's feasibility rules and direction.
+    fn ranked_indices(&self, scores: &[Score], violations: &[Violation]) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..scores.len()).collect();
+        indices.sort_by(|&a, &b| self.compare(scores[a], violations[a], scores[b], violations[b]));
+        indices
+    }
+
+    /// `Ordering::Less` means the first argument is the better (higher ranked).
+    fn compare(&self, s_a: Score, g_a: Violation, s_b: Score, g_b: Violation) -> Ordering {
+        let feasible_a = g_a.into_inner() == 0.0;
+        let feasible_b = g_b.into_inner() == 0.0;
+        match (feasible_a, feasible_b) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => g_a.cmp(&g_b),
+            (true, true) => match self.direction {
+                OptimizationDirection::Minimize => s_a.cmp(&s_b),
+                OptimizationDirection::Maximize => s_b.cmp(&s_a),
+            },
+        }
+    }
+
+    fn trial_wins(&self, s_trial: Score, g_trial: Violation, s_cur: Score, g_cur: Violation) -> bool {
+        self.compare(s_trial, g_trial, s_cur, g_cur) == Ordering::Less
+    }
+}
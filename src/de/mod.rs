@@ -0,0 +1,9 @@
+pub mod base;
+pub mod jade;
+
+pub use base::OptimizationDirection;
+
+pub mod simple;
+pub mod solver;
+pub mod strategy;
+pub mod termination;
@@ -4,7 +4,10 @@ use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::interface::{Problem, Score, Variable};
 
-use super::base::{CrossoverOperator, Initializer, MutationOperator, Selector};
+use super::base::{
+    CrossoverOperator, Initializer, MutationContext, MutationOperator, OptimizationDirection,
+    Selector, Violation,
+};
 
 pub struct SimpleInitializer {
     bounds: Vec<(NotNan<f64>, NotNan<f64>)>,
@@ -32,9 +35,10 @@ impl Initializer for SimpleInitializer {
         &self,
         problem: &dyn Problem,
         population_size: usize,
-    ) -> AnyResult<(Vec<Score>, Vec<Variable>)> {
+    ) -> AnyResult<(Vec<Score>, Vec<Violation>, Vec<Variable>)> {
         let mut rng = StdRng::seed_from_u64(self.seed);
         let mut scores = Vec::new();
+        let mut violations = Vec::new();
         let mut variables = Vec::new();
         for _ in 0..population_size {
             let mut x = Vec::new();
@@ -43,28 +47,47 @@ impl Initializer for SimpleInitializer {
             }
             let x = Variable::from_vec(x);
             let score = problem.evaluate(&x)?;
+            let violation = problem.violation(&x)?;
             variables.push(x);
             scores.push(score);
+            violations.push(violation);
         }
-        Ok((scores, variables))
+        Ok((scores, violations, variables))
     }
 }
 
-pub struct SimpleMutationOperator {
+pub struct SimpleMutationOperator<R = StdRng> {
     scale: NotNan<f64>,
+    rng: R,
 }
 
-impl SimpleMutationOperator {
+impl SimpleMutationOperator<StdRng> {
     pub fn new(scale: NotNan<f64>) -> Self {
-        Self { scale }
+        Self::with_seed(scale, StdRng::from_os_rng().random())
     }
 }
 
-impl MutationOperator for SimpleMutationOperator {
-    fn mutate_one(&self, current_population: &[Variable]) -> AnyResult<Variable> {
+impl<R: Rng + SeedableRng> SimpleMutationOperator<R> {
+    /// Builds the operator with its RNG seeded from `seed`, so mutation index
+    /// selection is reproducible. The RNG type `R` can be chosen to trade
+    /// throughput against statistical quality.
+    pub fn with_seed(scale: NotNan<f64>, seed: u64) -> Self {
+        Self {
+            scale,
+            rng: R::seed_from_u64(seed),
+        }
+    }
+}
+
+impl<R: Rng> MutationOperator for SimpleMutationOperator<R> {
+    fn mutate_one(
+        &mut self,
+        current_population: &[Variable],
+        _context: MutationContext,
+    ) -> AnyResult<Variable> {
         // randomly select three distinct indices
         let n = current_population.len();
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
         let mut indices = vec![];
         while indices.len() < 3 {
             let index = rng.random_range(0..n);
@@ -81,20 +104,32 @@ impl MutationOperator for SimpleMutationOperator {
     }
 }
 
-pub struct SimpleCrossoverOperator {
+pub struct SimpleCrossoverOperator<R = StdRng> {
     crossover_rate: NotNan<f64>,
+    rng: R,
 }
 
-impl SimpleCrossoverOperator {
+impl SimpleCrossoverOperator<StdRng> {
     pub fn new(crossover_rate: NotNan<f64>) -> Self {
-        Self { crossover_rate }
+        Self::with_seed(crossover_rate, StdRng::from_os_rng().random())
+    }
+}
+
+impl<R: Rng + SeedableRng> SimpleCrossoverOperator<R> {
+    /// Builds the operator with its RNG seeded from `seed`, so the per-gene
+    /// crossover draws are reproducible.
+    pub fn with_seed(crossover_rate: NotNan<f64>, seed: u64) -> Self {
+        Self {
+            crossover_rate,
+            rng: R::seed_from_u64(seed),
+        }
     }
 }
 
-impl CrossoverOperator for SimpleCrossoverOperator {
-    fn crossover_one(&self, v_current: &Variable, v_mutant: &Variable) -> AnyResult<Variable> {
+impl<R: Rng> CrossoverOperator for SimpleCrossoverOperator<R> {
+    fn crossover_one(&mut self, v_current: &Variable, v_mutant: &Variable) -> AnyResult<Variable> {
         let mut v_trial: Vec<f64> = Vec::with_capacity(v_current.len());
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
         for (x_current, x_mutant) in v_current.iter().zip(v_mutant.iter()) {
             let r: f64 = rng.random_range(0.0..1.0);
             let x_trial = if r < self.crossover_rate.into_inner() {
@@ -108,12 +143,25 @@ impl CrossoverOperator for SimpleCrossoverOperator {
     }
 }
 
+/// Unconstrained selector: the trial replaces its parent when it has the better
+/// objective in the configured [`OptimizationDirection`]. Constraint violations
+/// are carried through but not used in the comparison; use
+/// [`FeasibilitySelector`] for constrained problems.
 #[derive(Default)]
-pub struct SimpleSelector {}
+pub struct SimpleSelector {
+    direction: OptimizationDirection,
+}
 
 impl SimpleSelector {
+    /// Creates a minimizing selector.
     pub fn new() -> Self {
-        Self {}
+        Self {
+            direction: OptimizationDirection::Minimize,
+        }
+    }
+
+    pub fn with_direction(direction: OptimizationDirection) -> Self {
+        Self { direction }
     }
 }
 
@@ -122,14 +170,175 @@ impl Selector for SimpleSelector {
         &self,
         problem: &dyn Problem,
         s_current: Score,
+        g_current: Violation,
+        v_current: Variable,
+        v_trial: Variable,
+    ) -> AnyResult<(Score, Violation, Variable)> {
+        let s_trial = problem.evaluate(&v_trial)?;
+        if self.direction.is_better(s_trial, s_current) {
+            let g_trial = problem.violation(&v_trial)?;
+            Ok((s_trial, g_trial, v_trial))
+        } else {
+            Ok((s_current, g_current, v_current))
+        }
+    }
+
+    fn direction(&self) -> OptimizationDirection {
+        self.direction
+    }
+}
+
+/// Constraint-aware selector implementing Deb's feasibility rules:
+///
+/// 1. a feasible solution always beats an infeasible one,
+/// 2. between two feasible solutions the better objective in the configured
+///    [`OptimizationDirection`] wins (the lower one when minimizing),
+/// 3. between two infeasible solutions the smaller total violation wins.
+#[derive(Default)]
+pub struct FeasibilitySelector {
+    direction: OptimizationDirection,
+}
+
+impl FeasibilitySelector {
+    /// Creates a minimizing feasibility selector.
+    pub fn new() -> Self {
+        Self {
+            direction: OptimizationDirection::Minimize,
+        }
+    }
+
+    pub fn with_direction(direction: OptimizationDirection) -> Self {
+        Self { direction }
+    }
+}
+
+impl Selector for FeasibilitySelector {
+    fn select_one(
+        &self,
+        problem: &dyn Problem,
+        s_current: Score,
+        g_current: Violation,
         v_current: Variable,
         v_trial: Variable,
-    ) -> AnyResult<(Score, Variable)> {
+    ) -> AnyResult<(Score, Violation, Variable)> {
         let s_trial = problem.evaluate(&v_trial)?;
-        if s_trial < s_current {
-            Ok((s_trial, v_trial))
+        let g_trial = problem.violation(&v_trial)?;
+
+        let feasible_trial = g_trial.into_inner() == 0.0;
+        let feasible_current = g_current.into_inner() == 0.0;
+        let trial_wins = match (feasible_trial, feasible_current) {
+            (true, false) => true,
+            (false, true) => false,
+            (true, true) => self.direction.is_better(s_trial, s_current),
+            (false, false) => g_trial < g_current,
+        };
+
+        if trial_wins {
+            Ok((s_trial, g_trial, v_trial))
         } else {
-            Ok((s_current, v_current))
+            Ok((s_current, g_current, v_current))
+        }
+    }
+
+    fn direction(&self) -> OptimizationDirection {
+        self.direction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Maps the first gene to the objective and the second to the constraint
+    /// violation, so tests can drive selection with hand-built vectors.
+    struct ScoreViolationProblem;
+
+    impl Problem for ScoreViolationProblem {
+        fn evaluate(&self, v: &Variable) -> AnyResult<Score> {
+            Ok(NotNan::new(v.as_slice()[0])?)
         }
+
+        fn violation(&self, v: &Variable) -> AnyResult<NotNan<f64>> {
+            Ok(NotNan::new(v.as_slice()[1])?)
+        }
+    }
+
+    fn nn(x: f64) -> NotNan<f64> {
+        NotNan::new(x).unwrap()
+    }
+
+    fn point(score: f64, violation: f64) -> Variable {
+        Variable::from_vec(vec![score, violation])
+    }
+
+    #[test]
+    fn feasible_beats_infeasible_regardless_of_objective() {
+        let selector = FeasibilitySelector::new();
+        // current is infeasible; trial is feasible but has a worse objective
+        let (s, g, _) = selector
+            .select_one(
+                &ScoreViolationProblem,
+                nn(1.0),
+                nn(5.0),
+                point(1.0, 5.0),
+                point(10.0, 0.0),
+            )
+            .unwrap();
+        assert_eq!((s, g), (nn(10.0), nn(0.0)));
+    }
+
+    #[test]
+    fn feasible_pair_prefers_lower_objective() {
+        let selector = FeasibilitySelector::new();
+        let (s, _, _) = selector
+            .select_one(
+                &ScoreViolationProblem,
+                nn(5.0),
+                nn(0.0),
+                point(5.0, 0.0),
+                point(3.0, 0.0),
+            )
+            .unwrap();
+        assert_eq!(s, nn(3.0));
+    }
+
+    #[test]
+    fn infeasible_pair_prefers_smaller_violation() {
+        let selector = FeasibilitySelector::new();
+        // trial has the worse objective but the smaller violation, so it wins
+        let (s, g, _) = selector
+            .select_one(
+                &ScoreViolationProblem,
+                nn(1.0),
+                nn(5.0),
+                point(1.0, 5.0),
+                point(100.0, 2.0),
+            )
+            .unwrap();
+        assert_eq!((s, g), (nn(100.0), nn(2.0)));
+    }
+
+    #[test]
+    fn maximize_flips_the_acceptance_test() {
+        let trial = point(5.0, 0.0);
+        let current = point(3.0, 0.0);
+
+        let maximizing = SimpleSelector::with_direction(OptimizationDirection::Maximize);
+        let (s, _, _) = maximizing
+            .select_one(
+                &ScoreViolationProblem,
+                nn(3.0),
+                nn(0.0),
+                current.clone(),
+                trial.clone(),
+            )
+            .unwrap();
+        assert_eq!(s, nn(5.0), "maximization should accept the higher score");
+
+        let minimizing = SimpleSelector::new();
+        let (s, _, _) = minimizing
+            .select_one(&ScoreViolationProblem, nn(3.0), nn(0.0), current, trial)
+            .unwrap();
+        assert_eq!(s, nn(3.0), "minimization should keep the lower score");
     }
 }
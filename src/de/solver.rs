@@ -0,0 +1,215 @@
+use anyhow::Result as AnyResult;
+use ordered_float::NotNan;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::interface::{Problem, Score, Variable};
+
+use super::base::{
+    CrossoverOperator, Initializer, MutationContext, MutationOperator, OptimizationDirection,
+    Selector, Violation,
+};
+use super::simple::{
+    SimpleCrossoverOperator, SimpleInitializer, SimpleMutationOperator, SimpleSelector,
+};
+use super::termination::{
+    Callback, GenerationState, MaxGenerations, NoopCallback, Termination, TerminationSignal,
+};
+
+/// Convenience solver over the [`Simple`](super::simple) operators.
+pub type SimpleDifferentialEvolution = DifferentialEvolution<
+    SimpleInitializer,
+    SimpleMutationOperator,
+    SimpleCrossoverOperator,
+    SimpleSelector,
+>;
+
+impl SimpleDifferentialEvolution {
+    /// Builds a `Simple*`-operator solver whose whole run — initialization,
+    /// mutation index selection and crossover draws — is reproducible from one
+    /// master seed. The master seed is split into an independent sub-seed per
+    /// operator, so a given `u64` always yields a bit-for-bit identical run.
+    pub fn with_seed(
+        bounds: Vec<(NotNan<f64>, NotNan<f64>)>,
+        scale: NotNan<f64>,
+        crossover_rate: NotNan<f64>,
+        direction: OptimizationDirection,
+        master_seed: u64,
+    ) -> Self {
+        let mut master = StdRng::seed_from_u64(master_seed);
+        let initializer = SimpleInitializer::with_seed(bounds, master.random());
+        let mutation = SimpleMutationOperator::with_seed(scale, master.random());
+        let crossover = SimpleCrossoverOperator::with_seed(crossover_rate, master.random());
+        let selector = SimpleSelector::with_direction(direction);
+        Self::new(initializer, mutation, crossover, selector)
+    }
+}
+
+/// A differential-evolution driver wiring together an initializer, a mutation
+/// operator, a crossover operator and a selector.
+pub struct DifferentialEvolution<I, M, C, S> {
+    initializer: I,
+    mutation: M,
+    crossover: C,
+    selector: S,
+}
+
+impl<I, M, C, S> DifferentialEvolution<I, M, C, S>
+where
+    I: Initializer,
+    M: MutationOperator,
+    C: CrossoverOperator,
+    S: Selector,
+{
+    pub fn new(initializer: I, mutation: M, crossover: C, selector: S) -> Self {
+        Self {
+            initializer,
+            mutation,
+            crossover,
+            selector,
+        }
+    }
+
+    /// The optimization direction, taken from the selector so acceptance, the
+    /// reported best and the best-based mutation strategies never disagree.
+    fn direction(&self) -> OptimizationDirection {
+        self.selector.direction()
+    }
+
+    /// Run `n_iter` generations and return the best `(score, violation, variable)`.
+    pub fn solve(
+        &mut self,
+        problem: &dyn Problem,
+        population_size: usize,
+        n_iter: usize,
+    ) -> AnyResult<(Score, Violation, Variable)> {
+        self.optimize(
+            problem,
+            population_size,
+            &mut MaxGenerations::new(n_iter),
+            &mut NoopCallback,
+        )
+    }
+
+    /// Run until `termination` fires (or the `callback` requests a halt),
+    /// invoking `callback` once per generation with the current best.
+    pub fn optimize<T: Termination, CB: Callback>(
+        &mut self,
+        problem: &dyn Problem,
+        population_size: usize,
+        termination: &mut T,
+        callback: &mut CB,
+    ) -> AnyResult<(Score, Violation, Variable)> {
+        // DE mutation draws at least three distinct population members, so the
+        // operators' distinct-index sampling would spin forever below this.
+        anyhow::ensure!(
+            population_size >= 3,
+            "differential evolution requires a population size of at least 3, got {population_size}"
+        );
+
+        let (mut scores, mut violations, mut variables) =
+            self.initializer.initialize(problem, population_size)?;
+
+        let mut generation = 0;
+        loop {
+            let best = best_index(&scores, &violations, self.direction());
+            let state = GenerationState {
+                generation,
+                best_score: scores[best],
+                best_violation: violations[best],
+                best_variable: &variables[best],
+            };
+            if callback.on_generation(&state) == TerminationSignal::Stop {
+                break;
+            }
+            if termination.should_stop(&state) {
+                break;
+            }
+
+            let snapshot = variables.clone();
+            for i in 0..population_size {
+                let context = MutationContext {
+                    current_index: i,
+                    best_index: best,
+                };
+                let v_mutant = self.mutation.mutate_one(&snapshot, context)?;
+                let v_trial = self.crossover.crossover_one(&variables[i], &v_mutant)?;
+                let (s, g, v) = self.selector.select_one(
+                    problem,
+                    scores[i],
+                    violations[i],
+                    variables[i].clone(),
+                    v_trial,
+                )?;
+                scores[i] = s;
+                violations[i] = g;
+                variables[i] = v;
+            }
+            generation += 1;
+        }
+
+        let best = best_index(&scores, &violations, self.direction());
+        Ok((scores[best], violations[best], variables[best].clone()))
+    }
+}
+
+/// Index of the best individual under Deb's feasibility rules.
+fn best_index(
+    scores: &[Score],
+    violations: &[Violation],
+    direction: OptimizationDirection,
+) -> usize {
+    (0..scores.len())
+        .reduce(|best, i| {
+            let feasible_i = violations[i].into_inner() == 0.0;
+            let feasible_best = violations[best].into_inner() == 0.0;
+            let better = match (feasible_i, feasible_best) {
+                (true, false) => true,
+                (false, true) => false,
+                (true, true) => direction.is_better(scores[i], scores[best]),
+                (false, false) => violations[i] < violations[best],
+            };
+            if better {
+                i
+            } else {
+                best
+            }
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sphere;
+
+    impl Problem for Sphere {
+        fn evaluate(&self, v: &Variable) -> AnyResult<Score> {
+            let s = v.iter().map(|x| x * x).sum();
+            Ok(NotNan::new(s)?)
+        }
+    }
+
+    fn run(seed: u64) -> (Score, Variable) {
+        let bounds = vec![(NotNan::new(-5.0).unwrap(), NotNan::new(5.0).unwrap()); 3];
+        let mut de = SimpleDifferentialEvolution::with_seed(
+            bounds,
+            NotNan::new(0.8).unwrap(),
+            NotNan::new(0.9).unwrap(),
+            OptimizationDirection::Minimize,
+            seed,
+        );
+        let (score, _, variable) = de.solve(&Sphere, 20, 25).unwrap();
+        (score, variable)
+    }
+
+    #[test]
+    fn same_master_seed_is_bit_for_bit_reproducible() {
+        assert_eq!(run(42), run(42));
+    }
+
+    #[test]
+    fn different_master_seeds_diverge() {
+        assert_ne!(run(1), run(2));
+    }
+}
@@ -0,0 +1,159 @@
+use anyhow::Result as AnyResult;
+use ordered_float::NotNan;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::interface::Variable;
+
+use super::base::{MutationContext, MutationOperator};
+
+/// The standard family of differential-evolution mutation strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeStrategy {
+    /// `x_r0 + F*(x_r1 - x_r2)`
+    #[default]
+    Rand1,
+    /// `x_best + F*(x_r1 - x_r2)`
+    Best1,
+    /// `x_r0 + F*(x_r1 - x_r2) + F*(x_r3 - x_r4)`
+    Rand2,
+    /// `x_i + F*(x_best - x_i) + F*(x_r1 - x_r2)`
+    CurrentToBest1,
+    /// `x_best + F*(x_r1 - x_r2) + F*(x_r3 - x_r4)`
+    Best2,
+}
+
+impl DeStrategy {
+    /// Smallest population this strategy can sample distinct members from
+    /// without the index sampling looping forever.
+    pub fn min_population(&self) -> usize {
+        match self {
+            DeStrategy::Rand1 | DeStrategy::Best1 => 3,
+            DeStrategy::CurrentToBest1 => 4,
+            DeStrategy::Rand2 | DeStrategy::Best2 => 5,
+        }
+    }
+}
+
+/// A mutation operator that can apply any [`DeStrategy`], chosen at
+/// configuration time.
+pub struct StrategyMutationOperator<R = StdRng> {
+    strategy: DeStrategy,
+    scale: NotNan<f64>,
+    rng: R,
+}
+
+impl StrategyMutationOperator<StdRng> {
+    pub fn new(strategy: DeStrategy, scale: NotNan<f64>) -> Self {
+        Self::with_seed(strategy, scale, StdRng::from_os_rng().random())
+    }
+}
+
+impl<R: Rng + SeedableRng> StrategyMutationOperator<R> {
+    pub fn with_seed(strategy: DeStrategy, scale: NotNan<f64>, seed: u64) -> Self {
+        Self {
+            strategy,
+            scale,
+            rng: R::seed_from_u64(seed),
+        }
+    }
+}
+
+impl<R: Rng> StrategyMutationOperator<R> {
+    /// Draws `count` distinct indices from `0..n`, none of them in `exclude`.
+    fn sample_distinct(&mut self, n: usize, count: usize, exclude: &[usize]) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(count);
+        while indices.len() < count {
+            let index = self.rng.random_range(0..n);
+            if !exclude.contains(&index) && !indices.contains(&index) {
+                indices.push(index);
+            }
+        }
+        indices
+    }
+}
+
+impl<R: Rng> MutationOperator for StrategyMutationOperator<R> {
+    fn mutate_one(
+        &mut self,
+        current_population: &[Variable],
+        context: MutationContext,
+    ) -> AnyResult<Variable> {
+        let pop = current_population;
+        let n = pop.len();
+        anyhow::ensure!(
+            n >= self.strategy.min_population(),
+            "DE strategy {:?} requires a population size of at least {}, got {n}",
+            self.strategy,
+            self.strategy.min_population()
+        );
+        let f = self.scale.into_inner();
+        let i = context.current_index;
+        let best = context.best_index;
+
+        let v = match self.strategy {
+            DeStrategy::Rand1 => {
+                let r = self.sample_distinct(n, 3, &[]);
+                &pop[r[0]] + f * (&pop[r[1]] - &pop[r[2]])
+            }
+            DeStrategy::Best1 => {
+                let r = self.sample_distinct(n, 2, &[best]);
+                &pop[best] + f * (&pop[r[0]] - &pop[r[1]])
+            }
+            DeStrategy::Rand2 => {
+                let r = self.sample_distinct(n, 5, &[]);
+                let base = &pop[r[0]] + f * (&pop[r[1]] - &pop[r[2]]);
+                &base + f * (&pop[r[3]] - &pop[r[4]])
+            }
+            DeStrategy::CurrentToBest1 => {
+                let r = self.sample_distinct(n, 2, &[i, best]);
+                let base = &pop[i] + f * (&pop[best] - &pop[i]);
+                &base + f * (&pop[r[0]] - &pop[r[1]])
+            }
+            DeStrategy::Best2 => {
+                let r = self.sample_distinct(n, 4, &[best]);
+                let base = &pop[best] + f * (&pop[r[0]] - &pop[r[1]]);
+                &base + f * (&pop[r[2]] - &pop[r[3]])
+            }
+        };
+        Ok(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: f64) -> Variable {
+        Variable::from_vec(vec![x])
+    }
+
+    #[test]
+    fn best1_uses_the_best_individual_as_its_base() {
+        // every member except the best is identical, so the difference vector
+        // vanishes and the mutant must equal x_best.
+        let pop = vec![v(0.0), v(1.0), v(1.0), v(1.0)];
+        let mut op = StrategyMutationOperator::new(DeStrategy::Best1, NotNan::new(0.7).unwrap());
+        let ctx = MutationContext {
+            current_index: 2,
+            best_index: 0,
+        };
+        let mutant = op.mutate_one(&pop, ctx).unwrap();
+        assert_eq!(mutant, v(0.0));
+    }
+
+    #[test]
+    fn current_to_best1_moves_the_current_toward_the_best() {
+        // the two non-current, non-best members are identical, so only the
+        // current-to-best term survives: x_i + F*(x_best - x_i).
+        let pop = vec![v(0.0), v(2.0), v(9.0), v(9.0)];
+        let mut op =
+            StrategyMutationOperator::new(DeStrategy::CurrentToBest1, NotNan::new(0.5).unwrap());
+        let ctx = MutationContext {
+            current_index: 1,
+            best_index: 0,
+        };
+        let mutant = op.mutate_one(&pop, ctx).unwrap();
+        // 2 + 0.5 * (0 - 2) = 1.0
+        assert_eq!(mutant, v(1.0));
+    }
+}
@@ -0,0 +1,181 @@
+use std::time::{Duration, Instant};
+
+use crate::interface::{Score, Variable};
+
+use super::base::{OptimizationDirection, Violation};
+
+/// Snapshot handed to terminations and callbacks at the start of each generation.
+pub struct GenerationState<'a> {
+    /// Zero-based generation index.
+    pub generation: usize,
+    /// Best objective value seen in the current population.
+    pub best_score: Score,
+    /// Constraint violation of the best individual.
+    pub best_violation: Violation,
+    /// Best individual in the current population.
+    pub best_variable: &'a Variable,
+}
+
+/// Whether the driving loop should keep going or halt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminationSignal {
+    Continue,
+    Stop,
+}
+
+/// A stopping rule consulted once per generation.
+pub trait Termination {
+    fn should_stop(&mut self, state: &GenerationState) -> bool;
+}
+
+/// A per-generation observer that can also request early stopping.
+pub trait Callback {
+    fn on_generation(&mut self, state: &GenerationState) -> TerminationSignal;
+}
+
+impl<F> Callback for F
+where
+    F: FnMut(&GenerationState) -> TerminationSignal,
+{
+    fn on_generation(&mut self, state: &GenerationState) -> TerminationSignal {
+        self(state)
+    }
+}
+
+/// A callback that does nothing; used when the caller supplies none.
+pub struct NoopCallback;
+
+impl Callback for NoopCallback {
+    fn on_generation(&mut self, _state: &GenerationState) -> TerminationSignal {
+        TerminationSignal::Continue
+    }
+}
+
+/// Stops after a fixed number of generations.
+pub struct MaxGenerations {
+    max_iter: usize,
+}
+
+impl MaxGenerations {
+    pub fn new(max_iter: usize) -> Self {
+        Self { max_iter }
+    }
+}
+
+impl Termination for MaxGenerations {
+    fn should_stop(&mut self, state: &GenerationState) -> bool {
+        state.generation >= self.max_iter
+    }
+}
+
+/// Stops once the best objective reaches `target` in the given direction.
+pub struct TargetScore {
+    target: Score,
+    direction: OptimizationDirection,
+}
+
+impl TargetScore {
+    pub fn new(target: Score, direction: OptimizationDirection) -> Self {
+        Self { target, direction }
+    }
+}
+
+impl Termination for TargetScore {
+    fn should_stop(&mut self, state: &GenerationState) -> bool {
+        match self.direction {
+            OptimizationDirection::Minimize => state.best_score <= self.target,
+            OptimizationDirection::Maximize => state.best_score >= self.target,
+        }
+    }
+}
+
+/// Stops once a wall-clock budget has elapsed. The clock starts on the first
+/// check so setup time is not counted.
+pub struct WallClock {
+    budget: Duration,
+    start: Option<Instant>,
+}
+
+impl WallClock {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            start: None,
+        }
+    }
+}
+
+impl Termination for WallClock {
+    fn should_stop(&mut self, _state: &GenerationState) -> bool {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        start.elapsed() >= self.budget
+    }
+}
+
+/// Stops when the best score has not improved by more than `tolerance` over the
+/// last `window` generations.
+pub struct Stagnation {
+    window: usize,
+    tolerance: f64,
+    direction: OptimizationDirection,
+    best_so_far: Option<Score>,
+    stale: usize,
+}
+
+impl Stagnation {
+    pub fn new(window: usize, tolerance: f64, direction: OptimizationDirection) -> Self {
+        Self {
+            window,
+            tolerance,
+            direction,
+            best_so_far: None,
+            stale: 0,
+        }
+    }
+}
+
+impl Termination for Stagnation {
+    fn should_stop(&mut self, state: &GenerationState) -> bool {
+        let current = state.best_score.into_inner();
+        let improved = match self.best_so_far {
+            None => true,
+            Some(best) => match self.direction {
+                OptimizationDirection::Minimize => current < best.into_inner() - self.tolerance,
+                OptimizationDirection::Maximize => current > best.into_inner() + self.tolerance,
+            },
+        };
+
+        if improved {
+            self.best_so_far = Some(state.best_score);
+            self.stale = 0;
+        } else {
+            self.stale += 1;
+        }
+
+        self.stale >= self.window
+    }
+}
+
+/// Combines several terminations, stopping as soon as any one of them fires.
+pub struct AnyOf {
+    rules: Vec<Box<dyn Termination>>,
+}
+
+impl AnyOf {
+    pub fn new(rules: Vec<Box<dyn Termination>>) -> Self {
+        Self { rules }
+    }
+}
+
+impl Termination for AnyOf {
+    fn should_stop(&mut self, state: &GenerationState) -> bool {
+        // evaluate every rule first so stateful ones (e.g. Stagnation) keep
+        // updating; a short-circuiting `any` would skip later rules.
+        let stops: Vec<bool> = self
+            .rules
+            .iter_mut()
+            .map(|rule| rule.should_stop(state))
+            .collect();
+        stops.into_iter().any(|stop| stop)
+    }
+}
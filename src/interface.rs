@@ -0,0 +1,89 @@
+use std::ops::{Add, Mul, Sub};
+
+use anyhow::Result as AnyResult;
+use ordered_float::NotNan;
+
+/// Objective value of a candidate solution. Lower is better by convention; the
+/// [`OptimizationDirection`](crate::de::OptimizationDirection) decides how it is
+/// compared during selection.
+pub type Score = NotNan<f64>;
+
+/// A point in the search space, i.e. a real-valued decision vector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable {
+    values: Vec<f64>,
+}
+
+impl Variable {
+    pub fn from_vec(values: Vec<f64>) -> Self {
+        Self { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, f64> {
+        self.values.iter()
+    }
+
+    pub fn as_slice(&self) -> &[f64] {
+        &self.values
+    }
+}
+
+impl Sub for &Variable {
+    type Output = Variable;
+
+    fn sub(self, rhs: &Variable) -> Variable {
+        let values = self
+            .values
+            .iter()
+            .zip(rhs.values.iter())
+            .map(|(a, b)| a - b)
+            .collect();
+        Variable::from_vec(values)
+    }
+}
+
+impl Add<Variable> for &Variable {
+    type Output = Variable;
+
+    fn add(self, rhs: Variable) -> Variable {
+        let values = self
+            .values
+            .iter()
+            .zip(rhs.values.iter())
+            .map(|(a, b)| a + b)
+            .collect();
+        Variable::from_vec(values)
+    }
+}
+
+impl Mul<Variable> for f64 {
+    type Output = Variable;
+
+    fn mul(self, rhs: Variable) -> Variable {
+        let values = rhs.values.iter().map(|x| self * x).collect();
+        Variable::from_vec(values)
+    }
+}
+
+/// An optimization problem. Implementors evaluate a decision vector and,
+/// optionally, report how far it is from being feasible.
+pub trait Problem {
+    /// Objective value of `v`.
+    fn evaluate(&self, v: &Variable) -> AnyResult<Score>;
+
+    /// Total constraint violation `g(x) >= 0`, where `0` means feasible.
+    ///
+    /// The default treats every point as feasible, so unconstrained problems do
+    /// not have to implement this method.
+    fn violation(&self, _v: &Variable) -> AnyResult<NotNan<f64>> {
+        Ok(NotNan::new(0.0).unwrap())
+    }
+}